@@ -1,4 +1,4 @@
-#![no_std]
+#![cfg_attr(not(test), no_std)]
 #![doc = include_str!("../README.md")]
 #![deny(missing_docs)]
 #![deny(warnings)]
@@ -8,6 +8,11 @@
 //! This driver uses the `embedded-hal` traits to provide a hardware-independent interface
 //! to the HS3003 sensor. It supports reading both temperature and humidity measurements
 //! over I2C.
+//!
+//! # Features
+//!
+//! * `serde` - derives `Serialize`/`Deserialize` for [`Measurement`] and [`Status`]
+//! * `async` - adds [`Hs3003Async`], an `embedded-hal-async` based driver
 
 use embedded_hal::delay::DelayNs;
 use embedded_hal::i2c::I2c;
@@ -18,6 +23,36 @@ pub const HS3003_I2C_ADDRESS: u8 = 0x44;
 /// Measurement settling time in microseconds
 const MEASUREMENT_TIME_US: u32 = 100_000; // 100ms
 
+/// Command that enters programming mode; must be written within ~10ms of power-up
+const ENTER_PROGRAMMING_MODE_CMD: [u8; 2] = [0xA0, 0x00];
+
+/// Command byte prefix for a non-volatile register read in programming mode
+const READ_REGISTER_CMD: u8 = 0x1A;
+
+/// Command byte prefix for a non-volatile register write in programming mode
+const WRITE_REGISTER_CMD: u8 = 0x1C;
+
+/// Settling delay required after each non-volatile register access
+const REGISTER_ACCESS_DELAY_US: u32 = 2_000; // 2ms
+
+/// Non-volatile register holding the sensor's unique ID
+const REGISTER_SENSOR_ID: u8 = 0x1E;
+
+/// Non-volatile register holding temperature/humidity resolution configuration
+const REGISTER_RESOLUTION: u8 = 0x04;
+
+/// Lower bound of the sensor's specified operating temperature, in degrees Celsius
+const TEMPERATURE_MIN_C: f32 = -40.0;
+
+/// Upper bound of the sensor's specified operating temperature, in degrees Celsius
+const TEMPERATURE_MAX_C: f32 = 125.0;
+
+/// Lower bound of the sensor's specified relative humidity range, in percent
+const HUMIDITY_MIN_PCT: f32 = 0.0;
+
+/// Upper bound of the sensor's specified relative humidity range, in percent
+const HUMIDITY_MAX_PCT: f32 = 100.0;
+
 /// HS3003 temperature and humidity sensor driver
 #[derive(Debug)]
 pub struct Hs3003<I2C> {
@@ -27,11 +62,80 @@ pub struct Hs3003<I2C> {
 
 /// Measurement result containing temperature and humidity
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Measurement {
     /// Temperature in degrees Celsius
     pub temperature: f32,
     /// Relative humidity in percent
     pub humidity: f32,
+    /// Data-status of the reading reported by the sensor
+    pub status: Status,
+}
+
+/// Data-status field reported alongside every measurement
+///
+/// The sensor stores this in the upper two bits of the first humidity byte.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Status {
+    /// The reading is from a new, fully completed conversion
+    Valid,
+    /// The reading was already fetched once, or the conversion had not
+    /// finished when the host read the sensor
+    Stale,
+}
+
+impl Status {
+    fn from_bits(bits: u8) -> Self {
+        match bits & 0b11 {
+            0b00 => Status::Valid,
+            _ => Status::Stale,
+        }
+    }
+}
+
+impl Measurement {
+    /// Whether the parsed reading falls within the sensor's specified
+    /// operating envelope (roughly -40..125 °C and 0..100 %RH)
+    ///
+    /// The bounds are exclusive: the raw 14-bit fields saturate at exactly
+    /// these values for a disconnected bus (all `0xFF` or all `0x00`), so a
+    /// reading that lands precisely on the boundary is far more likely to be
+    /// garbage than a real measurement.
+    fn is_in_range(&self) -> bool {
+        self.temperature > TEMPERATURE_MIN_C
+            && self.temperature < TEMPERATURE_MAX_C
+            && self.humidity > HUMIDITY_MIN_PCT
+            && self.humidity < HUMIDITY_MAX_PCT
+    }
+
+    /// Temperature in degrees Fahrenheit
+    pub fn temperature_fahrenheit(&self) -> f32 {
+        self.temperature * 9.0 / 5.0 + 32.0
+    }
+
+    /// Absolute humidity in grams per cubic meter
+    ///
+    /// Derived from temperature and relative humidity using the Magnus
+    /// approximation; this is a pure calculation and requires no extra bus
+    /// traffic.
+    pub fn absolute_humidity(&self) -> f32 {
+        let t = self.temperature;
+        let rh = self.humidity;
+        2.1674 * (6.112 * libm::expf(17.67 * t / (t + 243.5)) * rh) / (273.15 + t)
+    }
+
+    /// Dew point in degrees Celsius
+    ///
+    /// Derived from temperature and relative humidity using the Magnus
+    /// approximation; this is a pure calculation and requires no extra bus
+    /// traffic.
+    pub fn dew_point(&self) -> f32 {
+        let t = self.temperature;
+        let rh = self.humidity;
+        let gamma = libm::logf(rh / 100.0) + 17.67 * t / (t + 243.5);
+        243.5 * gamma / (17.67 - gamma)
+    }
 }
 
 /// Errors that can occur when interacting with the sensor
@@ -39,6 +143,13 @@ pub struct Measurement {
 pub enum Error<E> {
     /// I2C bus error
     I2c(E),
+    /// The sensor reported stale data (conversion not yet complete, or the
+    /// result was already consumed by a previous read)
+    StaleData,
+    /// The parsed reading fell outside the sensor's specified operating
+    /// envelope (roughly -40..125 °C and 0..100 %RH), suggesting a
+    /// disconnected bus or broken wiring rather than a real measurement
+    OutOfRange,
 }
 
 impl<E> From<E> for Error<E> {
@@ -47,6 +158,150 @@ impl<E> From<E> for Error<E> {
     }
 }
 
+/// Conversion resolution for temperature or humidity, stored in non-volatile memory
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Resolution {
+    /// 8-bit resolution
+    Bits8,
+    /// 10-bit resolution
+    Bits10,
+    /// 12-bit resolution
+    Bits12,
+    /// 14-bit resolution (factory default)
+    Bits14,
+}
+
+impl Resolution {
+    fn from_bits(bits: u16) -> Self {
+        match bits & 0b11 {
+            0b00 => Resolution::Bits8,
+            0b01 => Resolution::Bits10,
+            0b10 => Resolution::Bits12,
+            _ => Resolution::Bits14,
+        }
+    }
+
+    fn to_bits(self) -> u16 {
+        match self {
+            Resolution::Bits8 => 0b00,
+            Resolution::Bits10 => 0b01,
+            Resolution::Bits12 => 0b10,
+            Resolution::Bits14 => 0b11,
+        }
+    }
+}
+
+/// HS3003 driver in programming mode, used to access the sensor's non-volatile
+/// configuration registers
+///
+/// Obtained from [`Hs3003::enter_programming_mode`], which must be called within
+/// ~10ms of the sensor powering up. Call [`Self::leave_programming_mode`] to get
+/// back a normal [`Hs3003`] for taking measurements.
+#[derive(Debug)]
+pub struct Hs3003Programming<I2C> {
+    i2c: I2C,
+    address: u8,
+}
+
+impl<I2C, E> Hs3003Programming<I2C>
+where
+    I2C: I2c<Error = E>,
+{
+    /// Reads a 16-bit non-volatile register
+    ///
+    /// Writes the read command and register address, waits for the documented
+    /// settling delay, then reads back the 3-byte response.
+    pub fn read_register<D>(&mut self, addr: u8, delay: &mut D) -> Result<u16, Error<E>>
+    where
+        D: DelayNs,
+    {
+        self.i2c
+            .write(self.address, &[READ_REGISTER_CMD, addr])?;
+        delay.delay_us(REGISTER_ACCESS_DELAY_US);
+
+        let mut buffer = [0u8; 3];
+        self.i2c.read(self.address, &mut buffer)?;
+        Ok(u16::from_be_bytes([buffer[1], buffer[2]]))
+    }
+
+    /// Writes a 16-bit non-volatile register
+    ///
+    /// Writes the write command, register address and value, then waits for
+    /// the documented settling delay for the write to take effect.
+    pub fn write_register<D>(&mut self, addr: u8, value: u16, delay: &mut D) -> Result<(), Error<E>>
+    where
+        D: DelayNs,
+    {
+        let [hi, lo] = value.to_be_bytes();
+        self.i2c
+            .write(self.address, &[WRITE_REGISTER_CMD, addr, hi, lo])?;
+        delay.delay_us(REGISTER_ACCESS_DELAY_US);
+        Ok(())
+    }
+
+    /// Reads the sensor's factory-programmed unique ID
+    pub fn sensor_id<D>(&mut self, delay: &mut D) -> Result<u16, Error<E>>
+    where
+        D: DelayNs,
+    {
+        self.read_register(REGISTER_SENSOR_ID, delay)
+    }
+
+    /// Sets the humidity conversion resolution
+    pub fn set_humidity_resolution<D>(
+        &mut self,
+        resolution: Resolution,
+        delay: &mut D,
+    ) -> Result<(), Error<E>>
+    where
+        D: DelayNs,
+    {
+        let current = self.read_register(REGISTER_RESOLUTION, delay)?;
+        let updated = (current & !0b11) | resolution.to_bits();
+        self.write_register(REGISTER_RESOLUTION, updated, delay)
+    }
+
+    /// Sets the temperature conversion resolution
+    pub fn set_temperature_resolution<D>(
+        &mut self,
+        resolution: Resolution,
+        delay: &mut D,
+    ) -> Result<(), Error<E>>
+    where
+        D: DelayNs,
+    {
+        let current = self.read_register(REGISTER_RESOLUTION, delay)?;
+        let updated = (current & !0b1100) | (resolution.to_bits() << 2);
+        self.write_register(REGISTER_RESOLUTION, updated, delay)
+    }
+
+    /// Reads back the current humidity conversion resolution
+    pub fn humidity_resolution<D>(&mut self, delay: &mut D) -> Result<Resolution, Error<E>>
+    where
+        D: DelayNs,
+    {
+        let current = self.read_register(REGISTER_RESOLUTION, delay)?;
+        Ok(Resolution::from_bits(current))
+    }
+
+    /// Reads back the current temperature conversion resolution
+    pub fn temperature_resolution<D>(&mut self, delay: &mut D) -> Result<Resolution, Error<E>>
+    where
+        D: DelayNs,
+    {
+        let current = self.read_register(REGISTER_RESOLUTION, delay)?;
+        Ok(Resolution::from_bits(current >> 2))
+    }
+
+    /// Leaves programming mode, returning a normal [`Hs3003`] ready to take measurements
+    pub fn leave_programming_mode(self) -> Hs3003<I2C> {
+        Hs3003 {
+            i2c: self.i2c,
+            address: self.address,
+        }
+    }
+}
+
 impl<I2C, E> Hs3003<I2C>
 where
     I2C: I2c<Error = E>,
@@ -92,6 +347,27 @@ where
         Self { i2c, address }
     }
 
+    /// Enters programming mode to access the sensor's non-volatile configuration
+    ///
+    /// Must be called within ~10ms of the sensor powering up, before any other
+    /// command is sent, so this takes ownership of a freshly powered-on `I2C`
+    /// bus directly rather than an existing [`Hs3003`]. Returns an
+    /// [`Hs3003Programming`] for reading and writing registers; call
+    /// [`Hs3003Programming::leave_programming_mode`] to get a normal [`Hs3003`]
+    /// back once configuration is done.
+    pub fn enter_programming_mode<D>(
+        mut i2c: I2C,
+        address: u8,
+        delay: &mut D,
+    ) -> Result<Hs3003Programming<I2C>, Error<E>>
+    where
+        D: DelayNs,
+    {
+        i2c.write(address, &ENTER_PROGRAMMING_MODE_CMD)?;
+        delay.delay_us(REGISTER_ACCESS_DELAY_US);
+        Ok(Hs3003Programming { i2c, address })
+    }
+
     /// Triggers a measurement and reads temperature and humidity
     ///
     /// This function:
@@ -107,7 +383,10 @@ where
     /// # Returns
     ///
     /// A `Result` containing a `Measurement` with temperature and humidity values,
-    /// or an `Error` if the operation fails.
+    /// or an `Error` if the operation fails. Returns `Error::StaleData` if the
+    /// sensor reports that the 100ms wait was not long enough for a fresh
+    /// conversion, or `Error::OutOfRange` if the reading falls outside the
+    /// sensor's specified range (for example, a disconnected bus).
     ///
     /// # Example
     ///
@@ -132,18 +411,76 @@ where
     where
         D: DelayNs,
     {
-        // Trigger measurement by writing to the sensor
-        self.i2c.write(self.address, &[0x00])?;
-
-        // Wait for measurement to complete
+        self.trigger_measurement()?;
         delay.delay_us(MEASUREMENT_TIME_US);
+        self.fetch_measurement()
+    }
 
-        // Read 4 bytes of data
+    /// Issues a measurement request without waiting for it to complete
+    ///
+    /// This only sends the measurement-request write. Callers must wait at
+    /// least 100ms (or poll [`Self::fetch_measurement`] until it stops
+    /// returning [`Error::StaleData`]) before the conversion is ready, which
+    /// lets the caller do other work on the bus or yield to an executor
+    /// instead of blocking on a fixed delay as [`Self::read`] does.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use embedded_hal_mock::eh1::i2c::{Mock as I2cMock, Transaction as I2cTransaction};
+    /// # use hs3003::Hs3003;
+    /// # let expectations = [
+    /// #     I2cTransaction::write(0x44, vec![0x00]),
+    /// # ];
+    /// # let i2c = I2cMock::new(&expectations);
+    /// let mut sensor = Hs3003::new(i2c);
+    /// sensor.trigger_measurement()?;
+    /// # let mut i2c = sensor.destroy();
+    /// # i2c.done();
+    /// # Ok::<(), hs3003::Error<embedded_hal::i2c::ErrorKind>>(())
+    /// ```
+    pub fn trigger_measurement(&mut self) -> Result<(), Error<E>> {
+        self.i2c.write(self.address, &[0x00])?;
+        Ok(())
+    }
+
+    /// Reads back the result of a previously triggered measurement
+    ///
+    /// Returns `Error::StaleData` if the conversion was not yet complete (or
+    /// the result was already consumed), so callers can retry after a short
+    /// additional delay instead of silently reusing the old reading. Returns
+    /// `Error::OutOfRange` if the reading falls outside the sensor's
+    /// specified range, which usually means the sensor is absent or the bus
+    /// is wired incorrectly.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use embedded_hal_mock::eh1::i2c::{Mock as I2cMock, Transaction as I2cTransaction};
+    /// # use hs3003::Hs3003;
+    /// # let expectations = [
+    /// #     I2cTransaction::read(0x44, vec![0x1F, 0xFF, 0x66, 0x64]),
+    /// # ];
+    /// # let i2c = I2cMock::new(&expectations);
+    /// let mut sensor = Hs3003::new(i2c);
+    /// let measurement = sensor.fetch_measurement()?;
+    /// // Use measurement.temperature and measurement.humidity
+    /// # let mut i2c = sensor.destroy();
+    /// # i2c.done();
+    /// # Ok::<(), hs3003::Error<embedded_hal::i2c::ErrorKind>>(())
+    /// ```
+    pub fn fetch_measurement(&mut self) -> Result<Measurement, Error<E>> {
         let mut buffer = [0u8; 4];
         self.i2c.read(self.address, &mut buffer)?;
 
-        // Parse the measurement
-        Ok(Self::parse_measurement(&buffer))
+        let measurement = Self::parse_measurement(&buffer);
+        if measurement.status == Status::Stale {
+            return Err(Error::StaleData);
+        }
+        if !measurement.is_in_range() {
+            return Err(Error::OutOfRange);
+        }
+        Ok(measurement)
     }
 
     /// Destroys the driver and returns the I2C interface
@@ -176,6 +513,9 @@ impl<I2C> Hs3003<I2C> {
     /// Humidity calculation: (raw_value / 16383) * 100
     /// Temperature calculation: ((raw_value / 16383) * 165) - 40
     fn parse_measurement(data: &[u8; 4]) -> Measurement {
+        // Top two bits of the first byte are the data-status field
+        let status = Status::from_bits(data[0] >> 6);
+
         // Extract humidity from first two bytes (top 14 bits)
         let humidity_raw = u16::from_be_bytes([data[0] & 0x3F, data[1]]);
         let humidity = (f32::from(humidity_raw) / 16383.0) * 100.0;
@@ -187,10 +527,78 @@ impl<I2C> Hs3003<I2C> {
         Measurement {
             temperature,
             humidity,
+            status,
         }
     }
 }
 
+/// Async variant of [`Hs3003`], built on `embedded-hal-async`
+///
+/// Mirrors the blocking API (`read`, `trigger_measurement`,
+/// `fetch_measurement`) so callers on async executors such as Embassy don't
+/// have to stall the whole core for the sensor's 100ms conversion time.
+#[cfg(feature = "async")]
+#[derive(Debug)]
+pub struct Hs3003Async<I2C> {
+    i2c: I2C,
+    address: u8,
+}
+
+#[cfg(feature = "async")]
+impl<I2C, E> Hs3003Async<I2C>
+where
+    I2C: embedded_hal_async::i2c::I2c<Error = E>,
+{
+    /// Creates a new async HS3003 driver instance with the default I2C address (0x44)
+    pub fn new(i2c: I2C) -> Self {
+        Self::new_with_address(i2c, HS3003_I2C_ADDRESS)
+    }
+
+    /// Creates a new async HS3003 driver instance with a custom I2C address
+    pub fn new_with_address(i2c: I2C, address: u8) -> Self {
+        Self { i2c, address }
+    }
+
+    /// Triggers a measurement, waits for it to complete, then reads and
+    /// parses it; the async equivalent of [`Hs3003::read`]
+    pub async fn read<D>(&mut self, delay: &mut D) -> Result<Measurement, Error<E>>
+    where
+        D: embedded_hal_async::delay::DelayNs,
+    {
+        self.trigger_measurement().await?;
+        delay.delay_us(MEASUREMENT_TIME_US).await;
+        self.fetch_measurement().await
+    }
+
+    /// Issues a measurement request without waiting for it to complete; the
+    /// async equivalent of [`Hs3003::trigger_measurement`]
+    pub async fn trigger_measurement(&mut self) -> Result<(), Error<E>> {
+        self.i2c.write(self.address, &[0x00]).await?;
+        Ok(())
+    }
+
+    /// Reads back the result of a previously triggered measurement; the
+    /// async equivalent of [`Hs3003::fetch_measurement`]
+    pub async fn fetch_measurement(&mut self) -> Result<Measurement, Error<E>> {
+        let mut buffer = [0u8; 4];
+        self.i2c.read(self.address, &mut buffer).await?;
+
+        let measurement = Hs3003::<I2C>::parse_measurement(&buffer);
+        if measurement.status == Status::Stale {
+            return Err(Error::StaleData);
+        }
+        if !measurement.is_in_range() {
+            return Err(Error::OutOfRange);
+        }
+        Ok(measurement)
+    }
+
+    /// Destroys the driver and returns the I2C interface
+    pub fn destroy(self) -> I2C {
+        self.i2c
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -215,6 +623,15 @@ mod tests {
             "Temperature was {}",
             measurement.temperature
         );
+        assert_eq!(measurement.status, Status::Valid);
+    }
+
+    #[test]
+    fn test_parse_measurement_stale_status() {
+        // Status bits 0b01 in the top two bits of the first humidity byte
+        let data = [0x5F, 0xFF, 0x66, 0x64];
+        let measurement = Hs3003::<()>::parse_measurement(&data);
+        assert_eq!(measurement.status, Status::Stale);
     }
 
     #[test]
@@ -236,4 +653,295 @@ mod tests {
     fn test_default_address() {
         assert_eq!(HS3003_I2C_ADDRESS, 0x44);
     }
+
+    #[test]
+    fn test_enter_and_leave_programming_mode() {
+        use embedded_hal_mock::eh1::delay::NoopDelay;
+        use embedded_hal_mock::eh1::i2c::{Mock as I2cMock, Transaction as I2cTransaction};
+
+        let expectations = [I2cTransaction::write(
+            HS3003_I2C_ADDRESS,
+            ENTER_PROGRAMMING_MODE_CMD.to_vec(),
+        )];
+        let i2c = I2cMock::new(&expectations);
+        let mut delay = NoopDelay::new();
+
+        let programming =
+            Hs3003::enter_programming_mode(i2c, HS3003_I2C_ADDRESS, &mut delay).unwrap();
+        let sensor = programming.leave_programming_mode();
+
+        let mut i2c = sensor.destroy();
+        i2c.done();
+    }
+
+    #[test]
+    fn test_fetch_measurement_out_of_range() {
+        use embedded_hal_mock::eh1::i2c::{Mock as I2cMock, Transaction as I2cTransaction};
+
+        // A disconnected bus reads back all zeros, which decodes to exactly
+        // 0% RH and -40°C: the boundary of the spec range, not a real reading.
+        let expectations = [I2cTransaction::read(0x44, vec![0x00, 0x00, 0x00, 0x00])];
+        let i2c = I2cMock::new(&expectations);
+        let mut sensor = Hs3003::new(i2c);
+
+        let result = sensor.fetch_measurement();
+        assert_eq!(result, Err(Error::OutOfRange));
+
+        let mut i2c = sensor.destroy();
+        i2c.done();
+    }
+
+    #[test]
+    fn test_read_register() {
+        use embedded_hal_mock::eh1::delay::NoopDelay;
+        use embedded_hal_mock::eh1::i2c::{Mock as I2cMock, Transaction as I2cTransaction};
+
+        let expectations = [
+            I2cTransaction::write(HS3003_I2C_ADDRESS, vec![READ_REGISTER_CMD, REGISTER_SENSOR_ID]),
+            I2cTransaction::read(HS3003_I2C_ADDRESS, vec![0x00, 0x12, 0x34]),
+        ];
+        let i2c = I2cMock::new(&expectations);
+        let mut programming = Hs3003Programming {
+            i2c,
+            address: HS3003_I2C_ADDRESS,
+        };
+        let mut delay = NoopDelay::new();
+
+        let value = programming.read_register(REGISTER_SENSOR_ID, &mut delay).unwrap();
+        assert_eq!(value, 0x1234);
+
+        programming.i2c.done();
+    }
+
+    #[test]
+    fn test_write_register() {
+        use embedded_hal_mock::eh1::delay::NoopDelay;
+        use embedded_hal_mock::eh1::i2c::{Mock as I2cMock, Transaction as I2cTransaction};
+
+        let expectations = [I2cTransaction::write(
+            HS3003_I2C_ADDRESS,
+            vec![WRITE_REGISTER_CMD, REGISTER_RESOLUTION, 0x00, 0x05],
+        )];
+        let i2c = I2cMock::new(&expectations);
+        let mut programming = Hs3003Programming {
+            i2c,
+            address: HS3003_I2C_ADDRESS,
+        };
+        let mut delay = NoopDelay::new();
+
+        programming
+            .write_register(REGISTER_RESOLUTION, 0x0005, &mut delay)
+            .unwrap();
+
+        programming.i2c.done();
+    }
+
+    #[test]
+    fn test_sensor_id() {
+        use embedded_hal_mock::eh1::delay::NoopDelay;
+        use embedded_hal_mock::eh1::i2c::{Mock as I2cMock, Transaction as I2cTransaction};
+
+        let expectations = [
+            I2cTransaction::write(HS3003_I2C_ADDRESS, vec![READ_REGISTER_CMD, REGISTER_SENSOR_ID]),
+            I2cTransaction::read(HS3003_I2C_ADDRESS, vec![0x00, 0xAB, 0xCD]),
+        ];
+        let i2c = I2cMock::new(&expectations);
+        let mut programming = Hs3003Programming {
+            i2c,
+            address: HS3003_I2C_ADDRESS,
+        };
+        let mut delay = NoopDelay::new();
+
+        assert_eq!(programming.sensor_id(&mut delay).unwrap(), 0xABCD);
+
+        programming.i2c.done();
+    }
+
+    #[test]
+    fn test_set_humidity_resolution() {
+        use embedded_hal_mock::eh1::delay::NoopDelay;
+        use embedded_hal_mock::eh1::i2c::{Mock as I2cMock, Transaction as I2cTransaction};
+
+        // Current register is 8-bit/8-bit (0x0000); setting humidity to 12-bit
+        // should only touch bits 0-1, leaving the temperature bits untouched.
+        let expectations = [
+            I2cTransaction::write(HS3003_I2C_ADDRESS, vec![READ_REGISTER_CMD, REGISTER_RESOLUTION]),
+            I2cTransaction::read(HS3003_I2C_ADDRESS, vec![0x00, 0x00, 0x00]),
+            I2cTransaction::write(
+                HS3003_I2C_ADDRESS,
+                vec![WRITE_REGISTER_CMD, REGISTER_RESOLUTION, 0x00, 0x02],
+            ),
+        ];
+        let i2c = I2cMock::new(&expectations);
+        let mut programming = Hs3003Programming {
+            i2c,
+            address: HS3003_I2C_ADDRESS,
+        };
+        let mut delay = NoopDelay::new();
+
+        programming
+            .set_humidity_resolution(Resolution::Bits12, &mut delay)
+            .unwrap();
+
+        programming.i2c.done();
+    }
+
+    #[test]
+    fn test_set_temperature_resolution() {
+        use embedded_hal_mock::eh1::delay::NoopDelay;
+        use embedded_hal_mock::eh1::i2c::{Mock as I2cMock, Transaction as I2cTransaction};
+
+        // Current register is 8-bit/8-bit (0x0000); setting temperature to
+        // 12-bit should only touch bits 2-3, leaving the humidity bits untouched.
+        let expectations = [
+            I2cTransaction::write(HS3003_I2C_ADDRESS, vec![READ_REGISTER_CMD, REGISTER_RESOLUTION]),
+            I2cTransaction::read(HS3003_I2C_ADDRESS, vec![0x00, 0x00, 0x00]),
+            I2cTransaction::write(
+                HS3003_I2C_ADDRESS,
+                vec![WRITE_REGISTER_CMD, REGISTER_RESOLUTION, 0x00, 0x08],
+            ),
+        ];
+        let i2c = I2cMock::new(&expectations);
+        let mut programming = Hs3003Programming {
+            i2c,
+            address: HS3003_I2C_ADDRESS,
+        };
+        let mut delay = NoopDelay::new();
+
+        programming
+            .set_temperature_resolution(Resolution::Bits12, &mut delay)
+            .unwrap();
+
+        programming.i2c.done();
+    }
+
+    #[test]
+    fn test_humidity_and_temperature_resolution() {
+        use embedded_hal_mock::eh1::delay::NoopDelay;
+        use embedded_hal_mock::eh1::i2c::{Mock as I2cMock, Transaction as I2cTransaction};
+
+        // 0b1000: humidity bits (0-1) are 8-bit, temperature bits (2-3) are 12-bit
+        let expectations = [
+            I2cTransaction::write(HS3003_I2C_ADDRESS, vec![READ_REGISTER_CMD, REGISTER_RESOLUTION]),
+            I2cTransaction::read(HS3003_I2C_ADDRESS, vec![0x00, 0x00, 0x08]),
+            I2cTransaction::write(HS3003_I2C_ADDRESS, vec![READ_REGISTER_CMD, REGISTER_RESOLUTION]),
+            I2cTransaction::read(HS3003_I2C_ADDRESS, vec![0x00, 0x00, 0x08]),
+        ];
+        let i2c = I2cMock::new(&expectations);
+        let mut programming = Hs3003Programming {
+            i2c,
+            address: HS3003_I2C_ADDRESS,
+        };
+        let mut delay = NoopDelay::new();
+
+        assert_eq!(
+            programming.humidity_resolution(&mut delay).unwrap(),
+            Resolution::Bits8
+        );
+        assert_eq!(
+            programming.temperature_resolution(&mut delay).unwrap(),
+            Resolution::Bits12
+        );
+
+        programming.i2c.done();
+    }
+
+    #[test]
+    fn test_absolute_humidity_and_dew_point() {
+        let measurement = Measurement {
+            temperature: 25.0,
+            humidity: 50.0,
+            status: Status::Valid,
+        };
+
+        assert!(
+            (measurement.absolute_humidity() - 11.5).abs() < 0.2,
+            "Absolute humidity was {}",
+            measurement.absolute_humidity()
+        );
+        assert!(
+            (measurement.dew_point() - 13.85).abs() < 0.2,
+            "Dew point was {}",
+            measurement.dew_point()
+        );
+    }
+
+    #[test]
+    fn test_temperature_fahrenheit() {
+        let measurement = Measurement {
+            temperature: 0.0,
+            humidity: 50.0,
+            status: Status::Valid,
+        };
+        assert!((measurement.temperature_fahrenheit() - 32.0).abs() < 0.01);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_round_trip() {
+        let measurement = Measurement {
+            temperature: 25.0,
+            humidity: 50.0,
+            status: Status::Valid,
+        };
+        let json = serde_json::to_string(&measurement).unwrap();
+        let decoded: Measurement = serde_json::from_str(&json).unwrap();
+        assert_eq!(measurement, decoded);
+    }
+
+    #[cfg(feature = "async")]
+    #[test]
+    fn test_async_fetch_measurement_stale() {
+        use embedded_hal_mock::eh1::i2c::{Mock as I2cMock, Transaction as I2cTransaction};
+
+        // Status bits 0b01 in the top two bits of the first humidity byte
+        let expectations = [I2cTransaction::read(0x44, vec![0x5F, 0xFF, 0x66, 0x64])];
+        let i2c = I2cMock::new(&expectations);
+        let mut sensor = Hs3003Async::new(i2c);
+
+        let result = futures::executor::block_on(sensor.fetch_measurement());
+        assert_eq!(result, Err(Error::StaleData));
+
+        let mut i2c = sensor.destroy();
+        i2c.done();
+    }
+
+    #[cfg(feature = "async")]
+    #[test]
+    fn test_async_fetch_measurement_out_of_range() {
+        use embedded_hal_mock::eh1::i2c::{Mock as I2cMock, Transaction as I2cTransaction};
+
+        // A disconnected bus reads back all zeros, which decodes to exactly
+        // 0% RH and -40°C: the boundary of the spec range, not a real reading.
+        let expectations = [I2cTransaction::read(0x44, vec![0x00, 0x00, 0x00, 0x00])];
+        let i2c = I2cMock::new(&expectations);
+        let mut sensor = Hs3003Async::new(i2c);
+
+        let result = futures::executor::block_on(sensor.fetch_measurement());
+        assert_eq!(result, Err(Error::OutOfRange));
+
+        let mut i2c = sensor.destroy();
+        i2c.done();
+    }
+
+    #[cfg(feature = "async")]
+    #[test]
+    fn test_async_read() {
+        use embedded_hal_mock::eh1::delay::NoopDelay;
+        use embedded_hal_mock::eh1::i2c::{Mock as I2cMock, Transaction as I2cTransaction};
+
+        let expectations = [
+            I2cTransaction::write(0x44, vec![0x00]),
+            I2cTransaction::read(0x44, vec![0x1F, 0xFF, 0x66, 0x64]),
+        ];
+        let i2c = I2cMock::new(&expectations);
+        let mut sensor = Hs3003Async::new(i2c);
+        let mut delay = NoopDelay::new();
+
+        let measurement = futures::executor::block_on(sensor.read(&mut delay)).unwrap();
+        assert!((measurement.humidity - 50.0).abs() < 0.1);
+
+        let mut i2c = sensor.destroy();
+        i2c.done();
+    }
 }